@@ -0,0 +1,43 @@
+// Copyright 2024 RISC Zero, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![no_main]
+
+use num_bigint::BigUint;
+use risc0_bigint2::field::{modadd, modinv, modmul, modsub};
+use risc0_zkvm::guest::env;
+
+risc0_zkvm::guest::entry!(main);
+
+/// Doubles an affine Short-Weierstrass point (x, y) over Fp with curve
+/// coefficient `a`. Short-circuits to the point at infinity when the input
+/// already is infinity, or when y = 0 (the 2-torsion case, where 2y has no
+/// inverse).
+fn main() {
+    let (x, y, inf, a, prime): (BigUint, BigUint, bool, BigUint, BigUint) = env::read();
+
+    let result = if inf || y == BigUint::from(0u32) {
+        (BigUint::from(0u32), BigUint::from(0u32), true)
+    } else {
+        let three_x_sq = modmul(&BigUint::from(3u32), &modmul(&x, &x, &prime), &prime);
+        let num = modadd(&three_x_sq, &a, &prime);
+        let two_y = modadd(&y, &y, &prime);
+        let lambda = modmul(&num, &modinv(&two_y, &prime), &prime);
+        let x3 = modsub(&modsub(&modmul(&lambda, &lambda, &prime), &x, &prime), &x, &prime);
+        let y3 = modsub(&modmul(&lambda, &modsub(&x, &x3, &prime), &prime), &y, &prime);
+        (x3, y3, false)
+    };
+
+    env::commit(&result);
+}
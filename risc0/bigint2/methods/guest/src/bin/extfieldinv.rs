@@ -0,0 +1,39 @@
+// Copyright 2024 RISC Zero, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![no_main]
+
+use num_bigint::BigUint;
+use risc0_bigint2::field::{modinv, modmul, modsub};
+use risc0_zkvm::guest::env;
+
+risc0_zkvm::guest::entry!(main);
+
+/// Inverts (a0 + a1*u) over Fp[u]/(u^2 - beta) via the field norm
+/// n = a0^2 - beta*a1^2, returning (a0*n^-1) + (-a1*n^-1)*u.
+fn main() {
+    let (a0, a1, prime, beta): (BigUint, BigUint, BigUint, BigUint) = env::read();
+
+    let a0_sq = modmul(&a0, &a0, &prime);
+    let a1_sq = modmul(&a1, &a1, &prime);
+    let beta_a1_sq = modmul(&beta, &a1_sq, &prime);
+    let norm = modsub(&a0_sq, &beta_a1_sq, &prime);
+    let norm_inv = modinv(&norm, &prime);
+
+    let real = modmul(&a0, &norm_inv, &prime);
+    let neg_a1 = modsub(&prime, &a1, &prime);
+    let imag = modmul(&neg_a1, &norm_inv, &prime);
+
+    env::commit(&(real, imag));
+}
@@ -0,0 +1,40 @@
+// Copyright 2024 RISC Zero, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![no_main]
+
+use num_bigint::BigUint;
+use risc0_bigint2::field::{modadd, modmul, modsub};
+use risc0_zkvm::guest::env;
+
+risc0_zkvm::guest::entry!(main);
+
+/// Multiplies (a0 + a1*u) * (b0 + b1*u) over Fp[u]/(u^2 - beta) using the
+/// Karatsuba arrangement, so only three base-field `modmul`s are needed.
+fn main() {
+    let (a0, a1, b0, b1, prime, beta): (BigUint, BigUint, BigUint, BigUint, BigUint, BigUint) =
+        env::read();
+
+    let t0 = modmul(&a0, &b0, &prime);
+    let t1 = modmul(&a1, &b1, &prime);
+    let a_sum = modadd(&a0, &a1, &prime);
+    let b_sum = modadd(&b0, &b1, &prime);
+    let t2 = modmul(&a_sum, &b_sum, &prime);
+
+    let beta_t1 = modmul(&beta, &t1, &prime);
+    let real = modadd(&t0, &beta_t1, &prime);
+    let imag = modsub(&modsub(&t2, &t0, &prime), &t1, &prime);
+
+    env::commit(&(real, imag));
+}
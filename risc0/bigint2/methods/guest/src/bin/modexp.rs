@@ -0,0 +1,43 @@
+// Copyright 2024 RISC Zero, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![no_main]
+
+use num_bigint::BigUint;
+use risc0_bigint2::field::modmul;
+use risc0_zkvm::guest::env;
+
+risc0_zkvm::guest::entry!(main);
+
+/// Computes base^exponent mod modulus via left-to-right square-and-multiply,
+/// reusing the accelerated `modmul` circuit for every squaring/multiply step.
+fn main() {
+    let (base, exponent, modulus): (BigUint, BigUint, BigUint) = env::read();
+
+    let result = if modulus == BigUint::from(1u32) {
+        BigUint::from(0u32)
+    } else {
+        let base = base % &modulus;
+        let mut acc = BigUint::from(1u32);
+        for i in (0..exponent.bits()).rev() {
+            acc = modmul(&acc, &acc, &modulus);
+            if exponent.bit(i) {
+                acc = modmul(&acc, &base, &modulus);
+            }
+        }
+        acc
+    };
+
+    env::commit(&result);
+}
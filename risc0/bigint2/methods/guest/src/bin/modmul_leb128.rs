@@ -0,0 +1,66 @@
+// Copyright 2024 RISC Zero, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![no_main]
+
+use num_bigint::BigUint;
+use risc0_bigint2::field::modmul;
+use risc0_zkvm::guest::env;
+
+risc0_zkvm::guest::entry!(main);
+
+/// LEB128-flavored counterpart to `modmul`: reads its three operands as
+/// variable-length byte groups (high bit of each byte signals continuation)
+/// instead of the fixed encoding the other guests use, and journals the
+/// result the same way.
+fn main() {
+    let lhs_bytes: Vec<u8> = env::read();
+    let rhs_bytes: Vec<u8> = env::read();
+    let modulus_bytes: Vec<u8> = env::read();
+
+    let lhs = decode_leb128(&lhs_bytes);
+    let rhs = decode_leb128(&rhs_bytes);
+    let modulus = decode_leb128(&modulus_bytes);
+
+    let result = modmul(&lhs, &rhs, &modulus);
+
+    env::commit(&encode_leb128(&result));
+}
+
+fn decode_leb128(bytes: &[u8]) -> BigUint {
+    let mut value = BigUint::from(0u8);
+    for (index, byte) in bytes.iter().enumerate() {
+        value |= BigUint::from(byte & 0x7f) << (7 * index);
+        if byte & 0x80 == 0 {
+            break;
+        }
+    }
+    value
+}
+
+fn encode_leb128(value: &BigUint) -> Vec<u8> {
+    let zero = BigUint::from(0u8);
+    let mut remaining = value.clone();
+    let mut bytes = Vec::new();
+    loop {
+        let group = (&remaining & &BigUint::from(0x7fu8)).to_bytes_le()[0];
+        remaining >>= 7u32;
+        if remaining == zero {
+            bytes.push(group);
+            break;
+        }
+        bytes.push(group | 0x80);
+    }
+    bytes
+}
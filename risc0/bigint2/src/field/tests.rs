@@ -17,13 +17,114 @@ extern crate num_bigint_dig as num_bigint;
 
 use num_bigint::BigUint;
 
-use risc0_bigint2_methods::{EXTFIELDSUB_ELF, EXTFIELDADD_ELF, MODADD_ELF, MODINV_ELF, MODMUL_ELF, MODSUB_ELF};
+use risc0_bigint2_methods::{
+    ECADD_ELF, ECDOUBLE_ELF, EXTFIELDADD_ELF, EXTFIELDINV_ELF, EXTFIELDMUL_ELF, EXTFIELDSUB_ELF,
+    MODADD_ELF, MODEXP_ELF, MODINV_ELF, MODMUL_ELF, MODMUL_LEB128_ELF, MODSUB_ELF,
+};
 use risc0_zkvm::{
-    get_prover_server, ExecutorEnv, ExecutorImpl, ExitCode, ProverOpts, VerifierContext,
+    get_prover_server, ExecutorEnv, ExecutorEnvBuilder, ExecutorImpl, ExitCode, Journal,
+    ProverOpts, VerifierContext,
 };
 use std::time::Instant;
 use test_log::test;
 
+fn leb128_bytes(value: &BigUint) -> Vec<u8> {
+    let zero = BigUint::from(0u8);
+    let mut remaining = value.clone();
+    let mut bytes = Vec::new();
+    loop {
+        let group = (&remaining & &BigUint::from(0x7fu8)).to_bytes_le()[0];
+        remaining >>= 7u32;
+        if remaining == zero {
+            bytes.push(group);
+            break;
+        }
+        bytes.push(group | 0x80);
+    }
+    bytes
+}
+
+fn leb128_value(bytes: &[u8]) -> BigUint {
+    let mut value = BigUint::from(0u8);
+    for (index, byte) in bytes.iter().enumerate() {
+        value |= BigUint::from(byte & 0x7f) << (7 * index);
+        if byte & 0x80 == 0 {
+            break;
+        }
+    }
+    value
+}
+
+/// Writes `value` onto `builder` as LEB128: little-endian 7-bit groups, each
+/// byte's high bit set iff another group follows. Opt-in alternative to the
+/// fixed encoding a plain `.write(&...)` of a `BigUint` uses by default, for
+/// shrinking IO/journal size on small or sparse values.
+fn write_leb128<'a, 'b>(
+    builder: &'b mut ExecutorEnvBuilder<'a>,
+    value: &BigUint,
+) -> &'b mut ExecutorEnvBuilder<'a> {
+    builder.write(&leb128_bytes(value)).unwrap()
+}
+
+/// Reassembles a `BigUint` from a journal holding LEB128 bytes in the same
+/// form [`write_leb128`] produces for guest input; the counterpart that wraps
+/// `journal.decode()` instead of decoding raw bytes directly.
+fn decode_leb128(journal: &Journal) -> BigUint {
+    let bytes: Vec<u8> = journal.decode().unwrap();
+    leb128_value(&bytes)
+}
+
+#[test]
+fn leb128_roundtrip() {
+    for case in [
+        BigUint::from(0u8),
+        BigUint::from(1u32),
+        BigUint::from(0x7fu32),
+        BigUint::from(0x80u32),
+        BigUint::from(0x1234_5678u64),
+        BigUint::parse_bytes(b"0102030405060708090A0B0C0D0E0F10", 16).unwrap(),
+    ] {
+        let encoded = leb128_bytes(&case);
+        assert_eq!(leb128_value(&encoded), case);
+    }
+}
+
+#[test]
+fn modmul_leb128() {
+    const LHS: &[u8] = b"04";
+    const RHS: &[u8] = b"07";
+    const MODULUS: &[u8] = b"05";
+    const EXPECTED: &[u8] = b"03";
+
+    let lhs = BigUint::parse_bytes(LHS, 16).unwrap();
+    let rhs = BigUint::parse_bytes(RHS, 16).unwrap();
+    let modulus = BigUint::parse_bytes(MODULUS, 16).unwrap();
+    let expected = BigUint::parse_bytes(EXPECTED, 16).unwrap();
+
+    let mut builder = ExecutorEnv::builder();
+    write_leb128(&mut builder, &lhs);
+    write_leb128(&mut builder, &rhs);
+    write_leb128(&mut builder, &modulus);
+    let env = builder.build().unwrap();
+
+    let now = Instant::now();
+    let session = ExecutorImpl::from_elf(env, MODMUL_LEB128_ELF)
+        .unwrap()
+        .run()
+        .unwrap();
+    assert_eq!(session.exit_code, ExitCode::Halted(0));
+    let result = decode_leb128(session.journal.as_ref().unwrap());
+    assert_eq!(result, expected);
+
+    let prover = get_prover_server(&ProverOpts::fast()).unwrap();
+    let prove_info = prover
+        .prove_session(&VerifierContext::default(), &session)
+        .unwrap();
+    let elapsed = now.elapsed();
+    tracing::info!("Runtime: {}", elapsed.as_millis());
+    tracing::info!("User cycles: {}", prove_info.stats.user_cycles);
+}
+
 #[test]
 fn modadd() {
     const LHS: &[u8] = b"04";
@@ -127,6 +228,111 @@ fn modmul() {
     tracing::info!("User cycles: {}", prove_info.stats.user_cycles);
 }
 
+#[test]
+fn modexp() {
+    const BASE: &[u8] = b"04";
+    const EXPONENT: &[u8] = b"0D";
+    const MODULUS: &[u8] = b"0B";
+    const EXPECTED: &[u8] = b"09";
+
+    let base = BigUint::parse_bytes(BASE, 16).unwrap();
+    let exponent = BigUint::parse_bytes(EXPONENT, 16).unwrap();
+    let modulus = BigUint::parse_bytes(MODULUS, 16).unwrap();
+    let expected = BigUint::parse_bytes(EXPECTED, 16).unwrap();
+
+    let env = ExecutorEnv::builder()
+        .write(&(base, exponent, modulus))
+        .unwrap()
+        .build()
+        .unwrap();
+    let now = Instant::now();
+    let session = ExecutorImpl::from_elf(env, MODEXP_ELF)
+        .unwrap()
+        .run()
+        .unwrap();
+    assert_eq!(session.exit_code, ExitCode::Halted(0));
+    let result: BigUint = session.journal.as_ref().unwrap().decode().unwrap();
+    assert_eq!(result, expected);
+
+    let prover = get_prover_server(&ProverOpts::fast()).unwrap();
+    let prove_info = prover
+        .prove_session(&VerifierContext::default(), &session)
+        .unwrap();
+    let elapsed = now.elapsed();
+    tracing::info!("Runtime: {}", elapsed.as_millis());
+    tracing::info!("User cycles: {}", prove_info.stats.user_cycles);
+}
+
+#[test]
+fn modexp_zero_exponent() {
+    const BASE: &[u8] = b"04";
+    const EXPONENT: &[u8] = b"00";
+    const MODULUS: &[u8] = b"0B";
+    const EXPECTED: &[u8] = b"01";
+
+    let base = BigUint::parse_bytes(BASE, 16).unwrap();
+    let exponent = BigUint::parse_bytes(EXPONENT, 16).unwrap();
+    let modulus = BigUint::parse_bytes(MODULUS, 16).unwrap();
+    let expected = BigUint::parse_bytes(EXPECTED, 16).unwrap();
+
+    let env = ExecutorEnv::builder()
+        .write(&(base, exponent, modulus))
+        .unwrap()
+        .build()
+        .unwrap();
+    let now = Instant::now();
+    let session = ExecutorImpl::from_elf(env, MODEXP_ELF)
+        .unwrap()
+        .run()
+        .unwrap();
+    assert_eq!(session.exit_code, ExitCode::Halted(0));
+    let result: BigUint = session.journal.as_ref().unwrap().decode().unwrap();
+    assert_eq!(result, expected);
+
+    let prover = get_prover_server(&ProverOpts::fast()).unwrap();
+    let prove_info = prover
+        .prove_session(&VerifierContext::default(), &session)
+        .unwrap();
+    let elapsed = now.elapsed();
+    tracing::info!("Runtime: {}", elapsed.as_millis());
+    tracing::info!("User cycles: {}", prove_info.stats.user_cycles);
+}
+
+#[test]
+fn modexp_modulus_one() {
+    const BASE: &[u8] = b"04";
+    const EXPONENT: &[u8] = b"0D";
+    const MODULUS: &[u8] = b"01";
+    const EXPECTED: &[u8] = b"00";
+
+    let base = BigUint::parse_bytes(BASE, 16).unwrap();
+    let exponent = BigUint::parse_bytes(EXPONENT, 16).unwrap();
+    let modulus = BigUint::parse_bytes(MODULUS, 16).unwrap();
+    let expected = BigUint::parse_bytes(EXPECTED, 16).unwrap();
+
+    let env = ExecutorEnv::builder()
+        .write(&(base, exponent, modulus))
+        .unwrap()
+        .build()
+        .unwrap();
+    let now = Instant::now();
+    let session = ExecutorImpl::from_elf(env, MODEXP_ELF)
+        .unwrap()
+        .run()
+        .unwrap();
+    assert_eq!(session.exit_code, ExitCode::Halted(0));
+    let result: BigUint = session.journal.as_ref().unwrap().decode().unwrap();
+    assert_eq!(result, expected);
+
+    let prover = get_prover_server(&ProverOpts::fast()).unwrap();
+    let prove_info = prover
+        .prove_session(&VerifierContext::default(), &session)
+        .unwrap();
+    let elapsed = now.elapsed();
+    tracing::info!("Runtime: {}", elapsed.as_millis());
+    tracing::info!("User cycles: {}", prove_info.stats.user_cycles);
+}
+
 #[test]
 fn modsub() {
     const LHS: &[u8] = b"04";
@@ -244,4 +450,346 @@ fn extfieldsub() {
     let elapsed = now.elapsed();
     tracing::info!("Runtime: {}", elapsed.as_millis());
     tracing::info!("User cycles: {}", prove_info.stats.user_cycles);
-}
\ No newline at end of file
+}
+
+#[test]
+fn extfieldmul() {
+    const LHS0: &[u8] = b"04";
+    const LHS1: &[u8] = b"06";
+    const RHS0: &[u8] = b"03";
+    const RHS1: &[u8] = b"04";
+    const PRIME: &[u8] = b"07";
+    // beta = -1 mod p, i.e. u^2 = -1
+    const BETA: &[u8] = b"06";
+    const EXPECTED0: &[u8] = b"02";
+    const EXPECTED1: &[u8] = b"06";
+
+    let lhs0 = BigUint::parse_bytes(LHS0, 16).unwrap();
+    let lhs1 = BigUint::parse_bytes(LHS1, 16).unwrap();
+    let rhs0 = BigUint::parse_bytes(RHS0, 16).unwrap();
+    let rhs1 = BigUint::parse_bytes(RHS1, 16).unwrap();
+    let prime = BigUint::parse_bytes(PRIME, 16).unwrap();
+    let beta = BigUint::parse_bytes(BETA, 16).unwrap();
+    let expected0 = BigUint::parse_bytes(EXPECTED0, 16).unwrap();
+    let expected1 = BigUint::parse_bytes(EXPECTED1, 16).unwrap();
+    let expected = (expected0, expected1);
+
+    let env = ExecutorEnv::builder()
+        .write(&(lhs0, lhs1, rhs0, rhs1, prime, beta))
+        .unwrap()
+        .build()
+        .unwrap();
+    let now = Instant::now();
+    let session = ExecutorImpl::from_elf(env, EXTFIELDMUL_ELF)
+        .unwrap()
+        .run()
+        .unwrap();
+    assert_eq!(session.exit_code, ExitCode::Halted(0));
+    let result: (BigUint, BigUint) = session.journal.as_ref().unwrap().decode().unwrap();
+    assert_eq!(result, expected);
+
+    let prover = get_prover_server(&ProverOpts::fast()).unwrap();
+    let prove_info = prover
+        .prove_session(&VerifierContext::default(), &session)
+        .unwrap();
+    let elapsed = now.elapsed();
+    tracing::info!("Runtime: {}", elapsed.as_millis());
+    tracing::info!("User cycles: {}", prove_info.stats.user_cycles);
+}
+
+#[test]
+fn extfieldinv() {
+    const LHS0: &[u8] = b"04";
+    const LHS1: &[u8] = b"06";
+    const PRIME: &[u8] = b"07";
+    // beta = -1 mod p, i.e. u^2 = -1
+    const BETA: &[u8] = b"06";
+    const EXPECTED0: &[u8] = b"06";
+    const EXPECTED1: &[u8] = b"05";
+
+    let lhs0 = BigUint::parse_bytes(LHS0, 16).unwrap();
+    let lhs1 = BigUint::parse_bytes(LHS1, 16).unwrap();
+    let prime = BigUint::parse_bytes(PRIME, 16).unwrap();
+    let beta = BigUint::parse_bytes(BETA, 16).unwrap();
+    let expected0 = BigUint::parse_bytes(EXPECTED0, 16).unwrap();
+    let expected1 = BigUint::parse_bytes(EXPECTED1, 16).unwrap();
+    let expected = (expected0, expected1);
+
+    let env = ExecutorEnv::builder()
+        .write(&(lhs0, lhs1, prime, beta))
+        .unwrap()
+        .build()
+        .unwrap();
+    let now = Instant::now();
+    let session = ExecutorImpl::from_elf(env, EXTFIELDINV_ELF)
+        .unwrap()
+        .run()
+        .unwrap();
+    assert_eq!(session.exit_code, ExitCode::Halted(0));
+    let result: (BigUint, BigUint) = session.journal.as_ref().unwrap().decode().unwrap();
+    assert_eq!(result, expected);
+
+    let prover = get_prover_server(&ProverOpts::fast()).unwrap();
+    let prove_info = prover
+        .prove_session(&VerifierContext::default(), &session)
+        .unwrap();
+    let elapsed = now.elapsed();
+    tracing::info!("Runtime: {}", elapsed.as_millis());
+    tracing::info!("User cycles: {}", prove_info.stats.user_cycles);
+}
+
+#[test]
+fn ecdouble() {
+    // Toy curve y^2 = x^3 + 2x + 2 mod 17, base point (5, 1).
+    const X: &[u8] = b"05";
+    const Y: &[u8] = b"01";
+    const A: &[u8] = b"02";
+    const PRIME: &[u8] = b"11";
+    const EXPECTED_X: &[u8] = b"06";
+    const EXPECTED_Y: &[u8] = b"03";
+
+    let x = BigUint::parse_bytes(X, 16).unwrap();
+    let y = BigUint::parse_bytes(Y, 16).unwrap();
+    let a = BigUint::parse_bytes(A, 16).unwrap();
+    let prime = BigUint::parse_bytes(PRIME, 16).unwrap();
+    let expected_x = BigUint::parse_bytes(EXPECTED_X, 16).unwrap();
+    let expected_y = BigUint::parse_bytes(EXPECTED_Y, 16).unwrap();
+    let expected = (expected_x, expected_y, false);
+
+    let env = ExecutorEnv::builder()
+        .write(&(x, y, false, a, prime))
+        .unwrap()
+        .build()
+        .unwrap();
+    let now = Instant::now();
+    let session = ExecutorImpl::from_elf(env, ECDOUBLE_ELF)
+        .unwrap()
+        .run()
+        .unwrap();
+    assert_eq!(session.exit_code, ExitCode::Halted(0));
+    let result: (BigUint, BigUint, bool) = session.journal.as_ref().unwrap().decode().unwrap();
+    assert_eq!(result, expected);
+
+    let prover = get_prover_server(&ProverOpts::fast()).unwrap();
+    let prove_info = prover
+        .prove_session(&VerifierContext::default(), &session)
+        .unwrap();
+    let elapsed = now.elapsed();
+    tracing::info!("Runtime: {}", elapsed.as_millis());
+    tracing::info!("User cycles: {}", prove_info.stats.user_cycles);
+}
+
+#[test]
+fn ecadd() {
+    // Toy curve y^2 = x^3 + 2x + 2 mod 17; (5, 1) + (6, 3) = (10, 6), where
+    // (6, 3) is 2*(5, 1) from the `ecdouble` test above.
+    const X1: &[u8] = b"05";
+    const Y1: &[u8] = b"01";
+    const X2: &[u8] = b"06";
+    const Y2: &[u8] = b"03";
+    const A: &[u8] = b"02";
+    const PRIME: &[u8] = b"11";
+    const EXPECTED_X: &[u8] = b"0A";
+    const EXPECTED_Y: &[u8] = b"06";
+
+    let x1 = BigUint::parse_bytes(X1, 16).unwrap();
+    let y1 = BigUint::parse_bytes(Y1, 16).unwrap();
+    let x2 = BigUint::parse_bytes(X2, 16).unwrap();
+    let y2 = BigUint::parse_bytes(Y2, 16).unwrap();
+    let a = BigUint::parse_bytes(A, 16).unwrap();
+    let prime = BigUint::parse_bytes(PRIME, 16).unwrap();
+    let expected_x = BigUint::parse_bytes(EXPECTED_X, 16).unwrap();
+    let expected_y = BigUint::parse_bytes(EXPECTED_Y, 16).unwrap();
+    let expected = (expected_x, expected_y, false);
+
+    let env = ExecutorEnv::builder()
+        .write(&(x1, y1, false, x2, y2, false, a, prime))
+        .unwrap()
+        .build()
+        .unwrap();
+    let now = Instant::now();
+    let session = ExecutorImpl::from_elf(env, ECADD_ELF)
+        .unwrap()
+        .run()
+        .unwrap();
+    assert_eq!(session.exit_code, ExitCode::Halted(0));
+    let result: (BigUint, BigUint, bool) = session.journal.as_ref().unwrap().decode().unwrap();
+    assert_eq!(result, expected);
+
+    let prover = get_prover_server(&ProverOpts::fast()).unwrap();
+    let prove_info = prover
+        .prove_session(&VerifierContext::default(), &session)
+        .unwrap();
+    let elapsed = now.elapsed();
+    tracing::info!("Runtime: {}", elapsed.as_millis());
+    tracing::info!("User cycles: {}", prove_info.stats.user_cycles);
+}
+
+#[test]
+fn ecadd_doubling() {
+    // ecadd must detect the equal-x case and internally double: (5, 1) + (5,
+    // 1) on y^2 = x^3 + 2x + 2 mod 17 should match the `ecdouble` test above.
+    const X1: &[u8] = b"05";
+    const Y1: &[u8] = b"01";
+    const X2: &[u8] = b"05";
+    const Y2: &[u8] = b"01";
+    const A: &[u8] = b"02";
+    const PRIME: &[u8] = b"11";
+    const EXPECTED_X: &[u8] = b"06";
+    const EXPECTED_Y: &[u8] = b"03";
+
+    let x1 = BigUint::parse_bytes(X1, 16).unwrap();
+    let y1 = BigUint::parse_bytes(Y1, 16).unwrap();
+    let x2 = BigUint::parse_bytes(X2, 16).unwrap();
+    let y2 = BigUint::parse_bytes(Y2, 16).unwrap();
+    let a = BigUint::parse_bytes(A, 16).unwrap();
+    let prime = BigUint::parse_bytes(PRIME, 16).unwrap();
+    let expected_x = BigUint::parse_bytes(EXPECTED_X, 16).unwrap();
+    let expected_y = BigUint::parse_bytes(EXPECTED_Y, 16).unwrap();
+    let expected = (expected_x, expected_y, false);
+
+    let env = ExecutorEnv::builder()
+        .write(&(x1, y1, false, x2, y2, false, a, prime))
+        .unwrap()
+        .build()
+        .unwrap();
+    let now = Instant::now();
+    let session = ExecutorImpl::from_elf(env, ECADD_ELF)
+        .unwrap()
+        .run()
+        .unwrap();
+    assert_eq!(session.exit_code, ExitCode::Halted(0));
+    let result: (BigUint, BigUint, bool) = session.journal.as_ref().unwrap().decode().unwrap();
+    assert_eq!(result, expected);
+
+    let prover = get_prover_server(&ProverOpts::fast()).unwrap();
+    let prove_info = prover
+        .prove_session(&VerifierContext::default(), &session)
+        .unwrap();
+    let elapsed = now.elapsed();
+    tracing::info!("Runtime: {}", elapsed.as_millis());
+    tracing::info!("User cycles: {}", prove_info.stats.user_cycles);
+}
+
+#[test]
+fn ecadd_identity() {
+    // The point at infinity is the additive identity: infinity + (5, 1)
+    // short-circuits to (5, 1) without touching the field arithmetic.
+    const X1: &[u8] = b"00";
+    const Y1: &[u8] = b"00";
+    const X2: &[u8] = b"05";
+    const Y2: &[u8] = b"01";
+    const A: &[u8] = b"02";
+    const PRIME: &[u8] = b"11";
+    const EXPECTED_X: &[u8] = b"05";
+    const EXPECTED_Y: &[u8] = b"01";
+
+    let x1 = BigUint::parse_bytes(X1, 16).unwrap();
+    let y1 = BigUint::parse_bytes(Y1, 16).unwrap();
+    let x2 = BigUint::parse_bytes(X2, 16).unwrap();
+    let y2 = BigUint::parse_bytes(Y2, 16).unwrap();
+    let a = BigUint::parse_bytes(A, 16).unwrap();
+    let prime = BigUint::parse_bytes(PRIME, 16).unwrap();
+    let expected_x = BigUint::parse_bytes(EXPECTED_X, 16).unwrap();
+    let expected_y = BigUint::parse_bytes(EXPECTED_Y, 16).unwrap();
+    let expected = (expected_x, expected_y, false);
+
+    let env = ExecutorEnv::builder()
+        .write(&(x1, y1, true, x2, y2, false, a, prime))
+        .unwrap()
+        .build()
+        .unwrap();
+    let now = Instant::now();
+    let session = ExecutorImpl::from_elf(env, ECADD_ELF)
+        .unwrap()
+        .run()
+        .unwrap();
+    assert_eq!(session.exit_code, ExitCode::Halted(0));
+    let result: (BigUint, BigUint, bool) = session.journal.as_ref().unwrap().decode().unwrap();
+    assert_eq!(result, expected);
+
+    let prover = get_prover_server(&ProverOpts::fast()).unwrap();
+    let prove_info = prover
+        .prove_session(&VerifierContext::default(), &session)
+        .unwrap();
+    let elapsed = now.elapsed();
+    tracing::info!("Runtime: {}", elapsed.as_millis());
+    tracing::info!("User cycles: {}", prove_info.stats.user_cycles);
+}
+
+#[test]
+fn ecadd_to_infinity() {
+    // A point plus its negation must produce the point at infinity: (5, 1) +
+    // (5, -1 mod 17) = (5, 1) + (5, 16) = infinity.
+    const X1: &[u8] = b"05";
+    const Y1: &[u8] = b"01";
+    const X2: &[u8] = b"05";
+    const Y2: &[u8] = b"10";
+    const A: &[u8] = b"02";
+    const PRIME: &[u8] = b"11";
+
+    let x1 = BigUint::parse_bytes(X1, 16).unwrap();
+    let y1 = BigUint::parse_bytes(Y1, 16).unwrap();
+    let x2 = BigUint::parse_bytes(X2, 16).unwrap();
+    let y2 = BigUint::parse_bytes(Y2, 16).unwrap();
+    let a = BigUint::parse_bytes(A, 16).unwrap();
+    let prime = BigUint::parse_bytes(PRIME, 16).unwrap();
+
+    let env = ExecutorEnv::builder()
+        .write(&(x1, y1, false, x2, y2, false, a, prime))
+        .unwrap()
+        .build()
+        .unwrap();
+    let now = Instant::now();
+    let session = ExecutorImpl::from_elf(env, ECADD_ELF)
+        .unwrap()
+        .run()
+        .unwrap();
+    assert_eq!(session.exit_code, ExitCode::Halted(0));
+    let result: (BigUint, BigUint, bool) = session.journal.as_ref().unwrap().decode().unwrap();
+    assert!(result.2);
+
+    let prover = get_prover_server(&ProverOpts::fast()).unwrap();
+    let prove_info = prover
+        .prove_session(&VerifierContext::default(), &session)
+        .unwrap();
+    let elapsed = now.elapsed();
+    tracing::info!("Runtime: {}", elapsed.as_millis());
+    tracing::info!("User cycles: {}", prove_info.stats.user_cycles);
+}
+
+#[test]
+fn ecdouble_infinity() {
+    // Doubling the point at infinity must short-circuit back to infinity.
+    const X: &[u8] = b"00";
+    const Y: &[u8] = b"00";
+    const A: &[u8] = b"02";
+    const PRIME: &[u8] = b"11";
+
+    let x = BigUint::parse_bytes(X, 16).unwrap();
+    let y = BigUint::parse_bytes(Y, 16).unwrap();
+    let a = BigUint::parse_bytes(A, 16).unwrap();
+    let prime = BigUint::parse_bytes(PRIME, 16).unwrap();
+
+    let env = ExecutorEnv::builder()
+        .write(&(x, y, true, a, prime))
+        .unwrap()
+        .build()
+        .unwrap();
+    let now = Instant::now();
+    let session = ExecutorImpl::from_elf(env, ECDOUBLE_ELF)
+        .unwrap()
+        .run()
+        .unwrap();
+    assert_eq!(session.exit_code, ExitCode::Halted(0));
+    let result: (BigUint, BigUint, bool) = session.journal.as_ref().unwrap().decode().unwrap();
+    assert!(result.2);
+
+    let prover = get_prover_server(&ProverOpts::fast()).unwrap();
+    let prove_info = prover
+        .prove_session(&VerifierContext::default(), &session)
+        .unwrap();
+    let elapsed = now.elapsed();
+    tracing::info!("Runtime: {}", elapsed.as_millis());
+    tracing::info!("User cycles: {}", prove_info.stats.user_cycles);
+}